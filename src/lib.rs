@@ -80,12 +80,105 @@
 //! and identically returned.
 //! This means that any regular syntax which such as field attributes, generics, etc. works as
 //! well.
+//!
+//! `enum`s are supported as well: tag exactly one variant with `#[default]` and that variant is
+//! used to build the returned value, with the same per-field `= value` syntax as structs.
+//! ```
+//! use short_default::default;
+//!
+//! default! {
+//!     enum Shape {
+//!         #[default]
+//!         Circle {
+//!             radius: f32 = 1.0,
+//!         },
+//!         Square(f32),
+//!     }
+//! }
+//!
+//! assert!(matches!(Shape::default(), Shape::Circle { radius } if radius == 1.0));
+//! ```
+//!
+//! A field default can also be written with the `into` conversion shorthand (or the equivalent
+//! `#[default(into)]` attribute) to avoid spelling out the conversion by hand:
+//! ```
+//! use short_default::default;
+//!
+//! default! {
+//!     struct Settings {
+//!         name: String = into "Short Default",
+//!     }
+//! }
+//!
+//! assert_eq!(Settings::default().name, "Short Default");
+//! ```
+//!
+//! The field type can be left out entirely when it is obvious from its default value, e.g. an
+//! integer, float, string, bool or char literal:
+//! ```
+//! use short_default::default;
+//!
+//! default! {
+//!     struct Config {
+//!         buffer_size = 10,
+//!     }
+//! }
+//!
+//! assert_eq!(Config::default().buffer_size, 10);
+//! ```
+//!
+//! Adding `#[builder]` also generates a `{Ident}Builder` with one setter per field. Fields with a
+//! default are optional; fields without one are required and `build()` is only available once
+//! every required field has been set:
+//! ```
+//! use short_default::default;
+//!
+//! default! {
+//!     #[builder]
+//!     pub struct Settings {
+//!         threads: usize = 4,
+//!         name: String,
+//!     }
+//! }
+//!
+//! let settings = Settings::builder().name("demo".to_string()).build();
+//! assert_eq!(settings.threads, 4);
+//! assert_eq!(settings.name, "demo");
+//! ```
+//!
+//! When a generic field's fallback value is `<Ty as Default>::default()`, every generic type
+//! parameter `T` that `Ty` mentions automatically gets a `T: Default` bound on the generated
+//! `impl Default`, so callers don't have to add it themselves:
+//! ```
+//! use short_default::default;
+//!
+//! default! {
+//!     struct Wrapper<T> {
+//!         value: T,
+//!     }
+//! }
+//!
+//! assert_eq!(Wrapper::<u32>::default().value, 0);
+//! ```
+//! When a field's type implements `Default` unconditionally (e.g. it doesn't actually depend on
+//! the generic parameter the way the macro assumes), the inferred bound can be suppressed with
+//! `#[no_default_bound(T)]`.
 
 use proc_macro::TokenStream;
 
+/// How a field's default expression should be turned into the field's value.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConversionStrategy {
+    /// Emit the default expression verbatim.
+    NoConversion,
+    /// Wrap the default expression in `core::convert::Into::into(..)`.
+    Into,
+}
+
 struct DefaultValue {
     #[allow(unused)]
     equal_sign: syn::Token![=],
+    conversion: ConversionStrategy,
     value: syn::Expr,
 }
 
@@ -93,42 +186,316 @@ struct DefaultValue {
 struct CustomField {
     field: syn::Field,
     default_value: Option<DefaultValue>,
+    conversion: ConversionStrategy,
 }
 
 impl syn::parse::Parse for DefaultValue {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let equal_sign: syn::Token![=] = input.parse()?;
+        let conversion = if input.peek(syn::Ident) {
+            let fork = input.fork();
+            let ident: syn::Ident = fork.parse()?;
+            if ident == "into" {
+                input.parse::<syn::Ident>()?;
+                ConversionStrategy::Into
+            } else {
+                ConversionStrategy::NoConversion
+            }
+        } else {
+            ConversionStrategy::NoConversion
+        };
         let value: syn::Expr = input.parse()?;
-        Ok(Self { equal_sign, value })
+        Ok(Self {
+            equal_sign,
+            conversion,
+            value,
+        })
+    }
+}
+
+/// Pulls a `#[default(into)]` attribute (if any) off of `field.attrs` and reports whether it
+/// requested the [`ConversionStrategy::Into`] shorthand.
+fn take_default_into_attr(field: &mut syn::Field) -> syn::Result<bool> {
+    let mut is_into = false;
+    let mut error = None;
+    field.attrs.retain(|attr| {
+        if !attr.path().is_ident("default") {
+            return true;
+        }
+        match attr.parse_args::<syn::Ident>() {
+            Ok(ident) if ident == "into" => is_into = true,
+            Ok(ident) => {
+                error.get_or_insert(syn::Error::new_spanned(
+                    ident,
+                    "expected `into`, the only supported `#[default(..)]` argument",
+                ));
+            }
+            Err(err) => {
+                error.get_or_insert(err);
+            }
+        }
+        false
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(is_into),
+    }
+}
+
+/// Pulls a `#[no_default_bound(T, U)]` attribute (if any) off of `attrs` and reports the names of
+/// the generic type parameters it names.
+fn take_no_default_bound_attr(
+    attrs: &mut Vec<syn::Attribute>,
+) -> syn::Result<std::collections::HashSet<String>> {
+    let mut skip = std::collections::HashSet::new();
+    let mut error = None;
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("no_default_bound") {
+            return true;
+        }
+        match attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+        ) {
+            Ok(idents) => skip.extend(idents.into_iter().map(|ident| ident.to_string())),
+            Err(err) => {
+                error.get_or_insert(err);
+            }
+        }
+        false
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(skip),
     }
 }
 
+/// Whether `ty` mentions the generic type parameter named `param` anywhere in its path, e.g.
+/// `type_mentions_param(parse_quote!(Vec<T>), "T")` is `true`.
+fn type_mentions_param(ty: &syn::Type, param: &str) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => {
+            let is_bare_param = type_path.qself.is_none()
+                && type_path.path.segments.len() == 1
+                && type_path.path.segments[0].ident == param
+                && type_path.path.segments[0].arguments.is_none();
+            is_bare_param
+                || type_path.path.segments.iter().any(|segment| match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| {
+                        matches!(arg, syn::GenericArgument::Type(ty) if type_mentions_param(ty, param))
+                    }),
+                    _ => false,
+                })
+        }
+        syn::Type::Reference(r) => type_mentions_param(&r.elem, param),
+        syn::Type::Paren(p) => type_mentions_param(&p.elem, param),
+        syn::Type::Group(g) => type_mentions_param(&g.elem, param),
+        syn::Type::Array(a) => type_mentions_param(&a.elem, param),
+        syn::Type::Slice(s) => type_mentions_param(&s.elem, param),
+        syn::Type::Tuple(t) => t.elems.iter().any(|elem| type_mentions_param(elem, param)),
+        _ => false,
+    }
+}
+
+/// The types of fields without a `= value` initializer, i.e. those whose default is the
+/// `<Ty as core::default::Default>::default()` fallback.
+fn fallback_default_field_types(fields: &CustomFields) -> Vec<&syn::Type> {
+    match fields {
+        CustomFields::Named(CustomFieldsNamed { named, .. }) => named
+            .iter()
+            .filter(|field| field.default_value.is_none())
+            .map(|field| &field.field.ty)
+            .collect(),
+        CustomFields::Unnamed(CustomFieldsUnnamed { unnamed, .. }) => unnamed
+            .iter()
+            .filter(|field| field.default_value.is_none())
+            .map(|field| &field.field.ty)
+            .collect(),
+        CustomFields::Unit => Vec::new(),
+    }
+}
+
+/// Builds the where-clause used by a generated `impl Default`: a clone of `generics`' own
+/// where-clause, with a `T: core::default::Default` predicate appended for every one of
+/// `generics`' type parameters that appears in a fallback field's type (unless that parameter is
+/// named in `skip`, the `#[no_default_bound(..)]` escape hatch). This mirrors the same
+/// over-approximation `#[derive(Default)]` makes: it can add a stricter bound than the fallback
+/// expression actually needs (e.g. a `Vec<T>` field never needs `T: Default`), which is exactly
+/// what the escape hatch is for.
+fn default_impl_where_clause(
+    generics: &syn::Generics,
+    fields: &CustomFields,
+    skip: &std::collections::HashSet<String>,
+) -> Option<syn::WhereClause> {
+    let type_params: Vec<&syn::Ident> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(type_param) => Some(&type_param.ident),
+            _ => None,
+        })
+        .filter(|ident| !skip.contains(&ident.to_string()))
+        .collect();
+    if type_params.is_empty() {
+        return generics.where_clause.clone();
+    }
+    let fallback_types = fallback_default_field_types(fields);
+    let mentioned_params = type_params.into_iter().filter(|ident| {
+        fallback_types
+            .iter()
+            .any(|ty| type_mentions_param(ty, &ident.to_string()))
+    });
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| syn::WhereClause {
+            where_token: <syn::Token![where]>::default(),
+            predicates: syn::punctuated::Punctuated::new(),
+        });
+    for ident in mentioned_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ident: core::default::Default));
+    }
+    if where_clause.predicates.is_empty() {
+        None
+    } else {
+        Some(where_clause)
+    }
+}
+
+/// Infers a concrete field type from a literal `= value` default, used when a field omits its
+/// `: Ty` annotation (e.g. `buffer_size = 10`). The struct definition needs a concrete type, so
+/// this only succeeds for literals whose Rust default type is unambiguous.
+fn infer_type_from_default(
+    ident: Option<&syn::Ident>,
+    default_value: &Option<DefaultValue>,
+) -> syn::Result<syn::Type> {
+    let Some(default_value) = default_value else {
+        let span = ident
+            .map(|ident| ident.span())
+            .unwrap_or_else(proc_macro2::Span::call_site);
+        return Err(syn::Error::new(
+            span,
+            "a field without a `: Ty` annotation needs a `= value` default to infer its type from",
+        ));
+    };
+    let cannot_infer = || {
+        syn::Error::new_spanned(
+            &default_value.value,
+            "cannot infer this field's type from its default value; annotate the field with an explicit `: Ty`",
+        )
+    };
+    let syn::Expr::Lit(syn::ExprLit { lit, .. }) = &default_value.value else {
+        return Err(cannot_infer());
+    };
+    let ty_name = match lit {
+        syn::Lit::Int(lit_int) if !lit_int.suffix().is_empty() => lit_int.suffix().to_string(),
+        syn::Lit::Int(_) => "i32".to_string(),
+        syn::Lit::Float(lit_float) if !lit_float.suffix().is_empty() => {
+            lit_float.suffix().to_string()
+        }
+        syn::Lit::Float(_) => "f64".to_string(),
+        syn::Lit::Str(_) => "String".to_string(),
+        syn::Lit::Bool(_) => "bool".to_string(),
+        syn::Lit::Char(_) => "char".to_string(),
+        _ => return Err(cannot_infer()),
+    };
+    syn::parse_str(&ty_name)
+}
+
 impl CustomField {
     fn parse_named(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let field = syn::Field::parse_named(input)?;
-        let default_value = if !input.peek(syn::Token![,]) {
-            Some(input.parse()?)
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis: syn::Visibility = input.parse()?;
+        let ident: syn::Ident = input.parse()?;
+        let explicit_ty = if input.peek(syn::Token![:]) {
+            input.parse::<syn::Token![:]>()?;
+            Some(input.parse::<syn::Type>()?)
         } else {
             None
         };
-        Ok(CustomField {
-            field,
-            default_value,
-        })
+        Self::finish(attrs, vis, Some(ident), explicit_ty, input)
     }
 
     fn parse_unnamed(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let field = syn::Field::parse_unnamed(input)?;
-        let default_value = if !input.peek(syn::Token![,]) {
-            Some(input.parse()?)
-        } else {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis: syn::Visibility = input.parse()?;
+        let explicit_ty = if input.peek(syn::Token![=]) {
             None
+        } else {
+            Some(input.parse::<syn::Type>()?)
+        };
+        Self::finish(attrs, vis, None, explicit_ty, input)
+    }
+
+    fn finish(
+        attrs: Vec<syn::Attribute>,
+        vis: syn::Visibility,
+        ident: Option<syn::Ident>,
+        explicit_ty: Option<syn::Type>,
+        input: syn::parse::ParseStream,
+    ) -> syn::Result<Self> {
+        let needs_inferred_ty = explicit_ty.is_none();
+        let mut field = syn::Field {
+            attrs,
+            vis,
+            mutability: syn::FieldMutability::None,
+            ident: ident.clone(),
+            colon_token: explicit_ty.is_some().then(<syn::Token![:]>::default),
+            ty: explicit_ty.unwrap_or_else(|| syn::Type::Verbatim(quote::quote!(_))),
         };
+        let attr_into = take_default_into_attr(&mut field)?;
+        let default_value: Option<DefaultValue> =
+            if !input.is_empty() && !input.peek(syn::Token![,]) {
+                Some(input.parse()?)
+            } else {
+                None
+            };
+        let mut inferred_str_literal = false;
+        if needs_inferred_ty {
+            field.ty = infer_type_from_default(ident.as_ref(), &default_value)?;
+            // A string literal infers to `String`, but the literal itself is `&str`; apply the
+            // same `.into()` the explicit `#[default(into)]` shorthand would, or the field
+            // wouldn't actually be the type we just inferred for it.
+            inferred_str_literal = matches!(
+                &default_value,
+                Some(DefaultValue {
+                    value: syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(_),
+                        ..
+                    }),
+                    ..
+                })
+            );
+        }
+        let conversion = Self::conversion(attr_into || inferred_str_literal, &default_value);
         Ok(CustomField {
             field,
             default_value,
+            conversion,
         })
     }
+
+    fn conversion(attr_into: bool, default_value: &Option<DefaultValue>) -> ConversionStrategy {
+        if attr_into
+            || matches!(default_value, Some(dv) if dv.conversion == ConversionStrategy::Into)
+        {
+            ConversionStrategy::Into
+        } else {
+            ConversionStrategy::NoConversion
+        }
+    }
+
+    fn formatted_default(&self) -> proc_macro2::TokenStream {
+        let ty = &self.field.ty;
+        match &self.default_value {
+            Some(DefaultValue { value, .. }) => match self.conversion {
+                ConversionStrategy::Into => quote::quote!(core::convert::Into::into(#value)),
+                ConversionStrategy::NoConversion => quote::quote!(#value),
+            },
+            None => quote::quote!(<#ty as core::default::Default>::default()),
+        }
+    }
 }
 
 struct CustomFieldsNamed {
@@ -140,23 +507,11 @@ impl CustomFieldsNamed {
     fn to_formatted_defaults(&self) -> Vec<proc_macro2::TokenStream> {
         self.named
             .iter()
-            .map(
-                |CustomField {
-                     field,
-                     default_value,
-                 }| {
-                    let ty = &field.ty;
-                    let ident = &field.ident;
-                    let value = match &default_value {
-                        Some(DefaultValue {
-                            equal_sign: _,
-                            value,
-                        }) => quote::quote!(#value),
-                        None => quote::quote!(<#ty as core::default::Default>::default()),
-                    };
-                    quote::quote!(#ident: #value)
-                },
-            )
+            .map(|custom_field| {
+                let ident = &custom_field.field.ident;
+                let value = custom_field.formatted_default();
+                quote::quote!(#ident: #value)
+            })
             .collect()
     }
 }
@@ -180,17 +535,7 @@ impl CustomFieldsUnnamed {
     fn to_formatted_defaults(&self) -> Vec<proc_macro2::TokenStream> {
         self.unnamed
             .iter()
-            .map(|field| {
-                let ty = &field.field.ty;
-                let value = match &field.default_value {
-                    Some(DefaultValue {
-                        equal_sign: _,
-                        value,
-                    }) => quote::quote!(#value),
-                    None => quote::quote!(<#ty as core::default::Default>::default()),
-                };
-                quote::quote!(#value)
-            })
+            .map(|custom_field| custom_field.formatted_default())
             .collect()
     }
 }
@@ -211,6 +556,231 @@ enum CustomFields {
     Unit,
 }
 
+impl CustomFields {
+    fn parse_variant_fields(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Paren) {
+            Ok(CustomFields::Unnamed(input.parse()?))
+        } else if input.peek(syn::token::Brace) {
+            Ok(CustomFields::Named(input.parse()?))
+        } else {
+            Ok(CustomFields::Unit)
+        }
+    }
+
+    fn into_fields(self) -> syn::Fields {
+        match self {
+            CustomFields::Named(CustomFieldsNamed { brace_token, named }) => {
+                syn::Fields::Named(syn::FieldsNamed {
+                    brace_token,
+                    named: syn::punctuated::Punctuated::from_iter(
+                        named.into_iter().map(|x| x.field),
+                    ),
+                })
+            }
+            CustomFields::Unnamed(CustomFieldsUnnamed {
+                paren_token,
+                unnamed,
+            }) => syn::Fields::Unnamed(syn::FieldsUnnamed {
+                paren_token,
+                unnamed: syn::punctuated::Punctuated::from_iter(
+                    unnamed.into_iter().map(|x| x.field),
+                ),
+            }),
+            CustomFields::Unit => syn::Fields::Unit,
+        }
+    }
+
+    fn to_formatted_defaults(&self) -> Vec<proc_macro2::TokenStream> {
+        match self {
+            CustomFields::Named(cfn) => cfn.to_formatted_defaults(),
+            CustomFields::Unnamed(cfu) => cfu.to_formatted_defaults(),
+            CustomFields::Unit => Vec::new(),
+        }
+    }
+}
+
+/// A single `enum` variant together with its optional `= value` field
+/// initializers and whether it is the variant tagged with `#[default]`.
+struct CustomVariant {
+    attrs: Vec<syn::Attribute>,
+    is_default: bool,
+    ident: syn::Ident,
+    fields: CustomFields,
+}
+
+impl CustomVariant {
+    fn to_variant_construction(&self, enum_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let CustomVariant { ident, fields, .. } = self;
+        match fields {
+            CustomFields::Named(_) => {
+                let entries = fields.to_formatted_defaults();
+                quote::quote!(#enum_ident::#ident { #(#entries),* })
+            }
+            CustomFields::Unnamed(_) => {
+                let entries = fields.to_formatted_defaults();
+                quote::quote!(#enum_ident::#ident(#(#entries),*))
+            }
+            CustomFields::Unit => quote::quote!(#enum_ident::#ident),
+        }
+    }
+}
+
+impl syn::parse::Parse for CustomVariant {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let parsed_attrs = input.call(syn::Attribute::parse_outer)?;
+        let mut is_default = false;
+        let mut attrs = Vec::with_capacity(parsed_attrs.len());
+        for attr in parsed_attrs {
+            if attr.path().is_ident("default") {
+                is_default = true;
+            } else {
+                attrs.push(attr);
+            }
+        }
+        let ident: syn::Ident = input.parse()?;
+        let fields = CustomFields::parse_variant_fields(input)?;
+        Ok(CustomVariant {
+            attrs,
+            is_default,
+            ident,
+            fields,
+        })
+    }
+}
+
+struct ParsedEnum {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    enum_token: syn::Token![enum],
+    ident: syn::Ident,
+    generics: syn::Generics,
+    brace_token: syn::token::Brace,
+    variants: syn::punctuated::Punctuated<CustomVariant, syn::Token![,]>,
+    /// Type parameters named in `#[no_default_bound(..)]`, excluded from automatic `Default`
+    /// bound inference.
+    no_default_bound: std::collections::HashSet<String>,
+}
+
+impl ParsedEnum {
+    fn into_item_enum(self) -> syn::ItemEnum {
+        let Self {
+            attrs,
+            vis,
+            enum_token,
+            ident,
+            generics,
+            brace_token,
+            variants,
+            no_default_bound: _,
+        } = self;
+        syn::ItemEnum {
+            attrs,
+            vis,
+            enum_token,
+            ident,
+            generics,
+            brace_token,
+            variants: syn::punctuated::Punctuated::from_iter(variants.into_iter().map(
+                |CustomVariant {
+                     attrs,
+                     is_default: _,
+                     ident,
+                     fields,
+                 }| syn::Variant {
+                    attrs,
+                    ident,
+                    fields: fields.into_fields(),
+                    discriminant: None,
+                },
+            )),
+        }
+    }
+
+    fn impl_default(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let mut default_variants = self.variants.iter().filter(|variant| variant.is_default);
+        let Some(default_variant) = default_variants.next() else {
+            return Err(syn::Error::new_spanned(
+                &self.ident,
+                "exactly one variant must be marked with `#[default]`, but none was found",
+            ));
+        };
+        if let Some(other) = default_variants.next() {
+            return Err(syn::Error::new_spanned(
+                &other.ident,
+                "only one variant may be marked with `#[default]`",
+            ));
+        }
+        let mut default_generics = self.generics.clone();
+        default_generics.where_clause = default_impl_where_clause(
+            &self.generics,
+            &default_variant.fields,
+            &self.no_default_bound,
+        );
+        let (impl_generics, ty_generics, where_clause) = default_generics.split_for_impl();
+        let construction = default_variant.to_variant_construction(&self.ident);
+        let ident = &self.ident;
+        Ok(quote::quote!(
+            impl #impl_generics core::default::Default for #ident #ty_generics #where_clause {
+                fn default() -> Self { #construction }
+            }
+        ))
+    }
+}
+
+impl syn::parse::Parse for ParsedEnum {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut attrs = input.call(syn::Attribute::parse_outer)?;
+        let no_default_bound = take_no_default_bound_attr(&mut attrs)?;
+        let vis = input.parse::<syn::Visibility>()?;
+        let enum_token = input.parse::<syn::Token![enum]>()?;
+        let ident = input.parse::<syn::Ident>()?;
+        let generics = input.parse::<syn::Generics>()?;
+        let where_clause = if input.peek(syn::Token![where]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let content;
+        let brace_token = syn::braced!(content in input);
+        let variants = content.parse_terminated(CustomVariant::parse, syn::Token![,])?;
+        Ok(ParsedEnum {
+            attrs,
+            vis,
+            enum_token,
+            ident,
+            generics: syn::Generics {
+                where_clause,
+                ..generics
+            },
+            brace_token,
+            variants,
+            no_default_bound,
+        })
+    }
+}
+
+/// Either a `struct` or an `enum` passed to [`default!`](crate::default!).
+enum Item {
+    Struct(Parsed),
+    Enum(ParsedEnum),
+}
+
+impl syn::parse::Parse for Item {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        let _ = fork.call(syn::Attribute::parse_outer)?;
+        let _ = fork.parse::<syn::Visibility>()?;
+        let lookahead = fork.lookahead1();
+        if lookahead.peek(syn::Token![enum]) {
+            Ok(Item::Enum(input.parse()?))
+        } else if lookahead.peek(syn::Token![struct]) {
+            Ok(Item::Struct(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
 struct Parsed {
     attrs: Vec<syn::Attribute>,
     vis: syn::Visibility,
@@ -219,6 +789,11 @@ struct Parsed {
     generics: syn::Generics,
     fields: CustomFields,
     semi_token: Option<syn::Token![;]>,
+    /// Whether `#[builder]` was present, requesting a companion builder type.
+    builder: bool,
+    /// Type parameters named in `#[no_default_bound(..)]`, excluded from automatic `Default`
+    /// bound inference.
+    no_default_bound: std::collections::HashSet<String>,
 }
 
 impl Parsed {
@@ -231,27 +806,10 @@ impl Parsed {
             generics,
             fields,
             semi_token,
+            builder: _,
+            no_default_bound: _,
         } = self;
-        let fields = match fields {
-            CustomFields::Named(CustomFieldsNamed { brace_token, named }) => {
-                syn::Fields::Named(syn::FieldsNamed {
-                    brace_token,
-                    named: syn::punctuated::Punctuated::from_iter(
-                        named.into_iter().map(|x| x.field),
-                    ),
-                })
-            }
-            CustomFields::Unnamed(CustomFieldsUnnamed {
-                paren_token,
-                unnamed,
-            }) => syn::Fields::Unnamed(syn::FieldsUnnamed {
-                paren_token,
-                unnamed: syn::punctuated::Punctuated::from_iter(
-                    unnamed.into_iter().map(|x| x.field),
-                ),
-            }),
-            CustomFields::Unit => syn::Fields::Unit,
-        };
+        let fields = fields.into_fields();
         syn::ItemStruct {
             attrs,
             vis,
@@ -273,8 +831,13 @@ impl Parsed {
             generics,
             fields,
             semi_token,
+            builder: _,
+            no_default_bound,
         } = &self;
-        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let mut default_generics = (*generics).clone();
+        default_generics.where_clause =
+            default_impl_where_clause(generics, fields, no_default_bound);
+        let (impl_generics, ty_generics, where_clause) = default_generics.split_for_impl();
         let fields = match fields {
             CustomFields::Named(cfn) => {
                 let entries = cfn.to_formatted_defaults();
@@ -339,7 +902,17 @@ fn data_struct(
 
 impl syn::parse::Parse for Parsed {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let parsed_attrs = input.call(syn::Attribute::parse_outer)?;
+        let mut builder = false;
+        let mut attrs = Vec::with_capacity(parsed_attrs.len());
+        for attr in parsed_attrs {
+            if attr.path().is_ident("builder") {
+                builder = true;
+            } else {
+                attrs.push(attr);
+            }
+        }
+        let no_default_bound = take_no_default_bound_attr(&mut attrs)?;
         let vis = input.parse::<syn::Visibility>()?;
         let struct_token = input.parse::<syn::Token![struct]>()?;
         let ident = input.parse::<syn::Ident>()?;
@@ -356,21 +929,292 @@ impl syn::parse::Parse for Parsed {
             },
             fields,
             semi_token,
+            builder,
+            no_default_bound,
+        })
+    }
+}
+
+/// Renders each of `generics`' parameters as the bare token used to *use* it (as opposed to
+/// *declare* it), e.g. `T`, `'a` or `N`, in declaration order.
+fn generic_param_tokens(generics: &syn::Generics) -> Vec<proc_macro2::TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Type(t) => {
+                let ident = &t.ident;
+                quote::quote!(#ident)
+            }
+            syn::GenericParam::Lifetime(l) => {
+                let lifetime = &l.lifetime;
+                quote::quote!(#lifetime)
+            }
+            syn::GenericParam::Const(c) => {
+                let ident = &c.ident;
+                quote::quote!(#ident)
+            }
         })
+        .collect()
+}
+
+/// A field of a `#[builder]` struct, with its computed default (if any) already formatted.
+struct BuilderField<'a> {
+    ident: &'a syn::Ident,
+    ty: &'a syn::Type,
+    default: Option<proc_macro2::TokenStream>,
+}
+
+impl Parsed {
+    /// Generates the typestate builder requested via `#[builder]`, or `None` if it wasn't
+    /// requested. Fields with a `= value` initializer are optional and pre-filled with that
+    /// default; fields without one are required and enforced at compile time: each is guarded by
+    /// its own marker type parameter that flips from `{Ident}BuilderUnset` to
+    /// `{Ident}BuilderSet<FieldTy>` once set, and `build()` is only implemented once every
+    /// marker is in the "set" state.
+    fn impl_builder(&self) -> syn::Result<Option<proc_macro2::TokenStream>> {
+        if !self.builder {
+            return Ok(None);
+        }
+        let named = match &self.fields {
+            CustomFields::Named(CustomFieldsNamed { named, .. }) => named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &self.ident,
+                    "#[builder] is only supported on structs with named fields",
+                ))
+            }
+        };
+
+        let struct_ident = &self.ident;
+        let vis = &self.vis;
+        let builder_ident = quote::format_ident!("{}Builder", struct_ident);
+        let unset_ident = quote::format_ident!("{}BuilderUnset", struct_ident);
+        let set_ident = quote::format_ident!("{}BuilderSet", struct_ident);
+
+        let fields: Vec<BuilderField> = named
+            .iter()
+            .map(|custom_field| BuilderField {
+                ident: custom_field.field.ident.as_ref().unwrap(),
+                ty: &custom_field.field.ty,
+                default: custom_field
+                    .default_value
+                    .as_ref()
+                    .map(|_| custom_field.formatted_default()),
+            })
+            .collect();
+        let required: Vec<&BuilderField> = fields.iter().filter(|f| f.default.is_none()).collect();
+        let marker_idents: Vec<syn::Ident> = (0..required.len())
+            .map(|i| quote::format_ident!("__ShortDefaultBuilderMarker{}", i))
+            .collect();
+
+        let original_args = generic_param_tokens(&self.generics);
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let marker_types = quote::quote!(
+            #[doc(hidden)]
+            #vis struct #unset_ident;
+            #[doc(hidden)]
+            #vis struct #set_ident<T>(#vis T);
+        );
+
+        // -- builder struct definition: markers default to `Unset` --
+        let mut builder_generics = self.generics.clone();
+        for marker in &marker_idents {
+            builder_generics
+                .params
+                .push(syn::parse_quote!(#marker = #unset_ident));
+        }
+        let (builder_impl_generics, builder_ty_generics, builder_where_clause) =
+            builder_generics.split_for_impl();
+        let mut next_marker = marker_idents.iter();
+        let field_decls = fields.iter().map(|f| {
+            let ident = f.ident;
+            match &f.default {
+                Some(_) => {
+                    let ty = f.ty;
+                    quote::quote!(#ident: #ty)
+                }
+                None => {
+                    let marker = next_marker.next().unwrap();
+                    quote::quote!(#ident: #marker)
+                }
+            }
+        });
+        let builder_struct = quote::quote!(
+            #vis struct #builder_ident #builder_generics #builder_where_clause {
+                #(#field_decls),*
+            }
+        );
+
+        // -- `new()`/`builder()`: every marker starts out `Unset` --
+        let all_unset_args: Vec<proc_macro2::TokenStream> = original_args
+            .iter()
+            .cloned()
+            .chain(marker_idents.iter().map(|_| quote::quote!(#unset_ident)))
+            .collect();
+        let new_field_inits = fields.iter().map(|f| {
+            let ident = f.ident;
+            match &f.default {
+                Some(default) => quote::quote!(#ident: #default),
+                None => quote::quote!(#ident: #unset_ident),
+            }
+        });
+        let new_impl = quote::quote!(
+            #[allow(clippy::new_without_default)]
+            impl #impl_generics #builder_ident <#(#all_unset_args),*> #where_clause {
+                #vis fn new() -> Self {
+                    Self {
+                        #(#new_field_inits),*
+                    }
+                }
+            }
+        );
+        let builder_fn = quote::quote!(
+            impl #impl_generics #struct_ident #ty_generics #where_clause {
+                #vis fn builder() -> #builder_ident <#(#all_unset_args),*> {
+                    #builder_ident::new()
+                }
+            }
+        );
+
+        // -- one setter per optional field, generic over every marker (the state is unchanged) --
+        let optional_setters = fields.iter().filter(|f| f.default.is_some()).map(|field| {
+            let ident = field.ident;
+            let ty = field.ty;
+            quote::quote!(
+                impl #builder_impl_generics #builder_ident #builder_ty_generics #builder_where_clause {
+                    #vis fn #ident(mut self, value: #ty) -> Self {
+                        self.#ident = value;
+                        self
+                    }
+                }
+            )
+        });
+
+        // -- one setter per required field, generic over every *other* marker --
+        let setters = required.iter().enumerate().map(|(marker_pos, field)| {
+            let ident = field.ident;
+            let ty = field.ty;
+            let mut setter_generics = self.generics.clone();
+            for (i, marker) in marker_idents.iter().enumerate() {
+                if i != marker_pos {
+                    setter_generics.params.push(syn::parse_quote!(#marker));
+                }
+            }
+            let (setter_impl_generics, _, setter_where_clause) = setter_generics.split_for_impl();
+            let self_args: Vec<proc_macro2::TokenStream> = original_args
+                .iter()
+                .cloned()
+                .chain(marker_idents.iter().enumerate().map(|(i, marker)| {
+                    if i == marker_pos {
+                        quote::quote!(#unset_ident)
+                    } else {
+                        quote::quote!(#marker)
+                    }
+                }))
+                .collect();
+            let out_args: Vec<proc_macro2::TokenStream> = original_args
+                .iter()
+                .cloned()
+                .chain(marker_idents.iter().enumerate().map(|(i, marker)| {
+                    if i == marker_pos {
+                        quote::quote!(#set_ident<#ty>)
+                    } else {
+                        quote::quote!(#marker)
+                    }
+                }))
+                .collect();
+            let field_inits = fields.iter().map(|f| {
+                let field_ident = f.ident;
+                if field_ident == ident {
+                    quote::quote!(#field_ident: #set_ident(value))
+                } else {
+                    quote::quote!(#field_ident: self.#field_ident)
+                }
+            });
+            quote::quote!(
+                impl #setter_impl_generics #builder_ident <#(#self_args),*> #setter_where_clause {
+                    #vis fn #ident(self, value: #ty) -> #builder_ident <#(#out_args),*> {
+                        #builder_ident {
+                            #(#field_inits),*
+                        }
+                    }
+                }
+            )
+        });
+
+        // -- `build()`: only implemented once every marker is `Set<FieldTy>` --
+        let build_args: Vec<proc_macro2::TokenStream> = original_args
+            .iter()
+            .cloned()
+            .chain(required.iter().map(|f| {
+                let ty = f.ty;
+                quote::quote!(#set_ident<#ty>)
+            }))
+            .collect();
+        let build_field_inits = fields.iter().map(|f| {
+            let ident = f.ident;
+            match &f.default {
+                Some(_) => quote::quote!(#ident: self.#ident),
+                None => quote::quote!(#ident: self.#ident.0),
+            }
+        });
+        let build_impl = quote::quote!(
+            impl #impl_generics #builder_ident <#(#build_args),*> #where_clause {
+                #vis fn build(self) -> #struct_ident #ty_generics {
+                    #struct_ident {
+                        #(#build_field_inits),*
+                    }
+                }
+            }
+        );
+
+        Ok(Some(quote::quote!(
+            #marker_types
+            #builder_struct
+            #new_impl
+            #builder_fn
+            #(#optional_setters)*
+            #(#setters)*
+            #build_impl
+        )))
     }
 }
 
 /// See the [crate-level](crate) documentation
 #[proc_macro]
 pub fn default(tokenstream: TokenStream) -> TokenStream {
-    let parsed: Parsed = syn::parse_macro_input!(tokenstream);
-    let default_impl = parsed.impl_default();
-    let item_struct = parsed.into_item_struct();
-    quote::quote!(
-        #item_struct
-        const _: () = {
-            #default_impl
-        };
-    )
-    .into()
+    let item: Item = syn::parse_macro_input!(tokenstream);
+    match item {
+        Item::Struct(parsed) => {
+            let builder = match parsed.impl_builder() {
+                Ok(builder) => builder,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            let default_impl = parsed.impl_default();
+            let item_struct = parsed.into_item_struct();
+            quote::quote!(
+                #item_struct
+                #builder
+                const _: () = {
+                    #default_impl
+                };
+            )
+            .into()
+        }
+        Item::Enum(parsed) => match parsed.impl_default() {
+            Ok(default_impl) => {
+                let item_enum = parsed.into_item_enum();
+                quote::quote!(
+                    #item_enum
+                    const _: () = {
+                        #default_impl
+                    };
+                )
+                .into()
+            }
+            Err(err) => err.to_compile_error().into(),
+        },
+    }
 }
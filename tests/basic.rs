@@ -51,6 +51,166 @@ fn generics() {
     assert_eq!(myvec.inner.len(), 0);
 }
 
+#[test]
+fn enum_default_variant() {
+    default!(
+        enum Shape {
+            #[default]
+            Circle {
+                radius: f32 = 1.0,
+            },
+            Square(f32),
+        }
+    );
+    let shape = Shape::default();
+    match shape {
+        Shape::Circle { radius } => assert_eq!(radius, 1.0),
+        Shape::Square(_) => panic!("expected the `#[default]` variant to be `Circle`"),
+    }
+
+    let square = Shape::Square(2.0);
+    match square {
+        Shape::Square(side) => assert_eq!(side, 2.0),
+        Shape::Circle { .. } => panic!("expected `Square`"),
+    }
+}
+
+#[test]
+fn trailing_field_without_default_or_comma() {
+    default!(
+        struct Named {
+            first: u8 = 1,
+            second: u8
+        }
+    );
+    let named = Named::default();
+    assert_eq!(named.first, 1);
+    assert_eq!(named.second, 0);
+
+    default!(
+        struct Unnamed(u8, u8);
+    );
+    let unnamed = Unnamed::default();
+    assert_eq!(unnamed.0, 0);
+    assert_eq!(unnamed.1, 0);
+}
+
+#[test]
+fn trailing_variant_field_without_default_or_comma() {
+    default!(
+        enum NamedVariant {
+            #[default]
+            First {
+                value: u8 = 1,
+            },
+            Second {
+                value: u8
+            },
+        }
+    );
+    assert!(matches!(
+        NamedVariant::default(),
+        NamedVariant::First { value: 1 }
+    ));
+    assert!(matches!(
+        NamedVariant::Second { value: 2 },
+        NamedVariant::Second { value: 2 }
+    ));
+
+    default!(
+        enum UnnamedVariant {
+            #[default]
+            First(u8),
+            Second(u8),
+        }
+    );
+    assert!(matches!(
+        UnnamedVariant::default(),
+        UnnamedVariant::First(0)
+    ));
+    assert!(matches!(
+        UnnamedVariant::Second(2),
+        UnnamedVariant::Second(2)
+    ));
+}
+
+#[test]
+fn into_conversion_shorthand() {
+    default!(
+        pub struct Profile {
+            name: String = into "Short Default",
+            #[default(into)]
+            nickname: String = "Shorty",
+        }
+    );
+    let profile = Profile::default();
+    assert_eq!(profile.name, "Short Default");
+    assert_eq!(profile.nickname, "Shorty");
+}
+
+#[test]
+fn inferred_field_type() {
+    default!(
+        pub struct Limits {
+            buffer_size = 10u16,
+            ratio = 0.5,
+            label = "limit",
+        }
+    );
+    let limits = Limits::default();
+    assert_eq!(limits.buffer_size, 10u16);
+    assert_eq!(limits.ratio, 0.5);
+    assert_eq!(limits.label, "limit");
+}
+
+#[test]
+fn builder() {
+    default!(
+        #[builder]
+        pub struct Settings {
+            threads: usize = 4,
+            name: String,
+        }
+    );
+    let settings = Settings::builder().name("demo".to_string()).build();
+    assert_eq!(settings.threads, 4);
+    assert_eq!(settings.name, "demo");
+
+    let settings = Settings::builder()
+        .threads(8)
+        .name("custom".to_string())
+        .build();
+    assert_eq!(settings.threads, 8);
+    assert_eq!(settings.name, "custom");
+}
+
+#[test]
+fn generic_default_bound_inference() {
+    default!(
+        struct Wrapper<T> {
+            value: T,
+        }
+    );
+    let wrapper = Wrapper::<u32>::default();
+    assert_eq!(wrapper.value, 0);
+}
+
+#[test]
+fn no_default_bound_escape_hatch() {
+    // Doesn't implement `Default`, so this only compiles if `Collection`'s generated `impl
+    // Default` does *not* end up requiring `T: Default`.
+    struct NotDefault;
+
+    default!(
+        #[no_default_bound(T)]
+        struct Collection<T> {
+            items: Vec<T>,
+        }
+    );
+    let collection = Collection::<NotDefault>::default();
+    assert_eq!(collection.items.len(), 0);
+}
+
 #[test]
 fn field_attributes() {
     use approx_derive::AbsDiffEq;